@@ -0,0 +1,238 @@
+use ode_solvers::*;
+
+use crate::State;
+
+/// Classical (Keplerian) orbital elements.
+pub(crate) struct KeplerianElements {
+    /// Semi-major axis, km.
+    pub(crate) a: f64,
+    /// Eccentricity.
+    pub(crate) e: f64,
+    /// Inclination, rad.
+    pub(crate) i: f64,
+    /// Right ascension of the ascending node, rad.
+    pub(crate) raan: f64,
+    /// Argument of perigee, rad.
+    pub(crate) argp: f64,
+    /// True anomaly, rad.
+    pub(crate) nu: f64,
+}
+
+/**
+Convert a Cartesian state vector to classical orbital elements.
+
+# Arguments
+* `state_vector` - The 1x6 array of cartesian position and velocity elements.
+* `mu` - Gravitational parameter of the central body.
+
+# Returns
+* `KeplerianElements` - The equivalent classical orbital elements.
+ */
+pub(crate) fn rv2coe(state_vector: &State, mu: f64) -> KeplerianElements {
+    let r = Vector3::new(state_vector[0], state_vector[1], state_vector[2]);
+    let v = Vector3::new(state_vector[3], state_vector[4], state_vector[5]);
+
+    let r_mag = r.norm();
+    let v_mag = v.norm();
+
+    let h = r.cross(&v);
+    let h_mag = h.norm();
+
+    let z_hat = Vector3::new(0.0, 0.0, 1.0);
+    let n = z_hat.cross(&h);
+    let n_mag = n.norm();
+
+    let e_vec = ((v_mag.powf(2.0) - mu / r_mag) * r - (r.dot(&v)) * v) / mu;
+    let e = e_vec.norm();
+
+    let xi = v_mag.powf(2.0) / 2.0 - mu / r_mag;
+    let a = -mu / (2.0 * xi);
+
+    let i = (h[2] / h_mag).acos();
+
+    let raan = if n_mag > 1e-10 {
+        let raan = (n[0] / n_mag).acos();
+        if n[1] < 0.0 {
+            2.0 * std::f64::consts::PI - raan
+        } else {
+            raan
+        }
+    } else {
+        0.0
+    };
+
+    let argp = if e > 1e-10 {
+        if n_mag > 1e-10 {
+            let argp = (n.dot(&e_vec) / (n_mag * e)).clamp(-1.0, 1.0).acos();
+            if e_vec[2] < 0.0 {
+                2.0 * std::f64::consts::PI - argp
+            } else {
+                argp
+            }
+        } else {
+            // Equatorial, eccentric: the node is undefined, so fold RAAN and
+            // argument of perigee into the longitude of periapsis, measured
+            // from the x-axis via the eccentricity vector.
+            let argp = (e_vec[0] / e).clamp(-1.0, 1.0).acos();
+            if e_vec[1] < 0.0 {
+                2.0 * std::f64::consts::PI - argp
+            } else {
+                argp
+            }
+        }
+    } else {
+        0.0
+    };
+
+    let nu = if e > 1e-10 {
+        let nu = (e_vec.dot(&r) / (e * r_mag)).clamp(-1.0, 1.0).acos();
+        if r.dot(&v) < 0.0 {
+            2.0 * std::f64::consts::PI - nu
+        } else {
+            nu
+        }
+    } else if n_mag > 1e-10 {
+        // Circular, inclined: measure true anomaly from the node vector.
+        let nu = (n.dot(&r) / (n_mag * r_mag)).clamp(-1.0, 1.0).acos();
+        if r[2] < 0.0 {
+            2.0 * std::f64::consts::PI - nu
+        } else {
+            nu
+        }
+    } else {
+        // Circular, equatorial: measure true anomaly from the x-axis.
+        let nu = (r[0] / r_mag).clamp(-1.0, 1.0).acos();
+        if r[1] < 0.0 {
+            2.0 * std::f64::consts::PI - nu
+        } else {
+            nu
+        }
+    };
+
+    KeplerianElements {
+        a,
+        e,
+        i,
+        raan,
+        argp,
+        nu,
+    }
+}
+
+/**
+Convert classical orbital elements to a Cartesian state vector.
+
+# Arguments
+* `elements` - The classical orbital elements.
+* `mu` - Gravitational parameter of the central body.
+
+# Returns
+* `State` - The equivalent 1x6 cartesian position and velocity state vector.
+ */
+pub(crate) fn coe2rv(elements: &KeplerianElements, mu: f64) -> State {
+    let p = elements.a * (1.0 - elements.e.powf(2.0));
+    let r_mag = p / (1.0 + elements.e * elements.nu.cos());
+
+    // Position and velocity in the perifocal (PQW) frame.
+    let r_pqw = Vector3::new(
+        r_mag * elements.nu.cos(),
+        r_mag * elements.nu.sin(),
+        0.0,
+    );
+    let v_pqw = Vector3::new(
+        -(mu / p).sqrt() * elements.nu.sin(),
+        (mu / p).sqrt() * (elements.e + elements.nu.cos()),
+        0.0,
+    );
+
+    let (cos_raan, sin_raan) = (elements.raan.cos(), elements.raan.sin());
+    let (cos_i, sin_i) = (elements.i.cos(), elements.i.sin());
+    let (cos_argp, sin_argp) = (elements.argp.cos(), elements.argp.sin());
+
+    // Perifocal-to-inertial rotation matrix (3-1-3 Euler sequence: RAAN, i, argp).
+    let r11 = cos_raan * cos_argp - sin_raan * sin_argp * cos_i;
+    let r12 = -cos_raan * sin_argp - sin_raan * cos_argp * cos_i;
+    let r21 = sin_raan * cos_argp + cos_raan * sin_argp * cos_i;
+    let r22 = -sin_raan * sin_argp + cos_raan * cos_argp * cos_i;
+    let r31 = sin_argp * sin_i;
+    let r32 = cos_argp * sin_i;
+
+    let r = Vector3::new(
+        r11 * r_pqw[0] + r12 * r_pqw[1],
+        r21 * r_pqw[0] + r22 * r_pqw[1],
+        r31 * r_pqw[0] + r32 * r_pqw[1],
+    );
+    let v = Vector3::new(
+        r11 * v_pqw[0] + r12 * v_pqw[1],
+        r21 * v_pqw[0] + r22 * v_pqw[1],
+        r31 * v_pqw[0] + r32 * v_pqw[1],
+    );
+
+    State::new(r[0], r[1], r[2], v[0], v[1], v[2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_relatively_eq(num_one: f64, num_two: f64, epsilon: f64) {
+        let diff = (num_two - num_one).abs();
+        assert!(diff <= epsilon, "{} vs {}, diff {}", num_one, num_two, diff);
+    }
+
+    #[test]
+    fn should_round_trip_rv_to_coe_to_rv() {
+        let mu = 398600.0;
+        let y0 = State::new(-2384.46, 5729.01, 3050.46, -7.36138, -2.98997, 1.64354);
+
+        let elements = rv2coe(&y0, mu);
+        let y_round_tripped = coe2rv(&elements, mu);
+
+        for idx in 0..6 {
+            assert_relatively_eq(y0[idx], y_round_tripped[idx], 1e-6);
+        }
+    }
+
+    #[test]
+    fn should_handle_circular_equatorial_orbit() {
+        let mu = 398600.0;
+        let r_mag = 7000.0;
+        let v_mag = (mu / r_mag).sqrt();
+        let y0 = State::new(r_mag, 0.0, 0.0, 0.0, v_mag, 0.0);
+
+        let elements = rv2coe(&y0, mu);
+
+        assert_relatively_eq(elements.a, r_mag, 1e-6);
+        assert_relatively_eq(elements.e, 0.0, 1e-9);
+        assert_relatively_eq(elements.i, 0.0, 1e-9);
+
+        let y_round_tripped = coe2rv(&elements, mu);
+        for idx in 0..6 {
+            assert_relatively_eq(y0[idx], y_round_tripped[idx], 1e-6);
+        }
+    }
+
+    #[test]
+    fn should_handle_eccentric_equatorial_orbit() {
+        let mu = 398600.0;
+        let elements = KeplerianElements {
+            a: 8000.0,
+            e: 0.2,
+            i: 0.0,
+            raan: 0.0,
+            argp: 50.0_f64.to_radians(),
+            nu: 120.0_f64.to_radians(),
+        };
+        let y0 = coe2rv(&elements, mu);
+
+        let recovered = rv2coe(&y0, mu);
+        assert_relatively_eq(recovered.a, elements.a, 1e-6);
+        assert_relatively_eq(recovered.e, elements.e, 1e-9);
+        assert_relatively_eq(recovered.i, 0.0, 1e-9);
+
+        let y_round_tripped = coe2rv(&recovered, mu);
+        for idx in 0..6 {
+            assert_relatively_eq(y0[idx], y_round_tripped[idx], 1e-6);
+        }
+    }
+}
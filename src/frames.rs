@@ -0,0 +1,78 @@
+use ode_solvers::*;
+
+use crate::{State, Time};
+
+/// The default rotation axis (ẑ) used by most body-centered rotating
+/// frames.
+pub(crate) const Z_AXIS: Vector3<f64> = Vector3::new(0.0, 0.0, 1.0);
+
+/**
+Express an inertial trajectory in a uniformly rotating, body-centered
+frame.
+
+At each sample time `t`, rotates the position and velocity by
+`R(-omega * (t - t0))` about `axis`; the velocity additionally has the
+frame's own rotation `omega x r` subtracted out, so a body that co-rotates
+with the frame appears nearly stationary.
+
+# Arguments
+* `trajectory` - The `(time, state)` pairs of an inertial trajectory, e.g.
+  from `propagate_dense`.
+* `omega` - The frame's constant rotation rate, rad/s.
+* `axis` - The rotation axis. Pass `Z_AXIS` for the common case.
+* `t0` - The epoch at which the rotating frame is aligned with the inertial
+  frame.
+
+# Returns
+* `trajectory` - The state vectors expressed in the rotating frame.
+ */
+pub(crate) fn to_rotating_frame(
+    trajectory: &[(Time, State)],
+    omega: f64,
+    axis: Vector3<f64>,
+    t0: Time,
+) -> Vec<State> {
+    let unit_axis = nalgebra::Unit::new_normalize(axis);
+    let omega_vec = unit_axis.into_inner() * omega;
+
+    trajectory
+        .iter()
+        .map(|(t, y)| {
+            let theta = -omega * (t - t0);
+            let rotation = nalgebra::Rotation3::from_axis_angle(&unit_axis, theta);
+
+            let r = Vector3::new(y[0], y[1], y[2]);
+            let v = Vector3::new(y[3], y[4], y[5]);
+
+            let r_rot = rotation * r;
+            let v_rot = rotation * (v - omega_vec.cross(&r));
+
+            State::new(r_rot[0], r_rot[1], r_rot[2], v_rot[0], v_rot[1], v_rot[2])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trajectory::propagate_dense;
+
+    #[test]
+    fn should_keep_co_rotating_circular_orbit_nearly_stationary() {
+        let mu = 398600.0;
+        let r_mag = 42164.0; // geostationary radius, km
+        let v_mag = (mu / r_mag).sqrt();
+        let omega = v_mag / r_mag;
+
+        let y0 = State::new(r_mag, 0.0, 0.0, 0.0, v_mag, 0.0);
+        let period = 2.0 * std::f64::consts::PI / omega;
+
+        let trajectory = propagate_dense(y0, mu, period, 60.0);
+        let rotating = to_rotating_frame(&trajectory, omega, Z_AXIS, 0.0);
+
+        for state in &rotating {
+            let position_drift = ((state[0] - r_mag).powf(2.0) + state[1].powf(2.0) + state[2].powf(2.0)).sqrt();
+            assert!(position_drift < 1.0, "position drift {} too large", position_drift);
+        }
+    }
+}
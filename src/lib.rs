@@ -1,16 +1,39 @@
 use ode_solvers::dopri5::*;
 use ode_solvers::*;
 
+mod elements;
+mod frames;
+mod monte_carlo;
+mod scenario;
+mod trajectory;
+
 type State = Vector6<f64>;
 type Time = f64;
 
+/// A gravitational perturber other than the central body, e.g. the Moon or
+/// the Sun. `position` gives the perturber's location at a given time so
+/// ephemeris data can be plugged in.
+struct PerturbingBody {
+    mu: f64,
+    position: fn(Time) -> Vector3<f64>,
+}
+
 struct Orbit {
     mu: f64,
+    /// J2 zonal-harmonic coefficient of the central body. `0.0` disables
+    /// oblateness and reproduces pure two-body Kepler motion exactly.
+    j2: f64,
+    /// Equatorial radius of the central body, used by the J2 term.
+    r_eq: f64,
+    /// Additional gravitational perturbers, e.g. third-body effects from the
+    /// Moon or the Sun. Empty reproduces pure two-body (plus J2) motion.
+    perturbing_bodies: Vec<PerturbingBody>,
 }
 
 impl System<State> for Orbit {
     /**
-    Kepler Orbit Equations of motion.
+    Kepler Orbit Equations of motion, optionally perturbed by the J2
+    zonal-harmonic oblateness of the central body.
 
     # Arguments
        * '_t' - The moment in time corresponding to a specific state.
@@ -18,13 +41,35 @@ impl System<State> for Orbit {
        * 'dy' -  The change in the state vector
     */
     fn system(&self, _t: Time, y: &State, dy: &mut State) {
-        let denominator: f64 = (y[0].powf(2.0) + y[1].powf(2.0) + y[2].powf(2.0)).powf(3.0 / 2.0);
+        let r2: f64 = y[0].powf(2.0) + y[1].powf(2.0) + y[2].powf(2.0);
+        let denominator: f64 = r2.powf(3.0 / 2.0);
         dy[0] = y[3];
         dy[1] = y[4];
         dy[2] = y[5];
         dy[3] = -self.mu * y[0] / denominator;
         dy[4] = -self.mu * y[1] / denominator;
         dy[5] = -self.mu * y[2] / denominator;
+
+        if self.j2 != 0.0 {
+            let r5: f64 = r2.powf(5.0 / 2.0);
+            let z2_over_r2: f64 = y[2].powf(2.0) / r2;
+            let j2_factor: f64 = -1.5 * self.j2 * self.mu * self.r_eq.powf(2.0) / r5;
+            dy[3] += j2_factor * y[0] * (1.0 - 5.0 * z2_over_r2);
+            dy[4] += j2_factor * y[1] * (1.0 - 5.0 * z2_over_r2);
+            dy[5] += j2_factor * y[2] * (3.0 - 5.0 * z2_over_r2);
+        }
+
+        if !self.perturbing_bodies.is_empty() {
+            let r = Vector3::new(y[0], y[1], y[2]);
+            for body in &self.perturbing_bodies {
+                let s = (body.position)(_t);
+                let s_minus_r = s - r;
+                let a = body.mu * (s_minus_r / s_minus_r.norm().powf(3.0) - s / s.norm().powf(3.0));
+                dy[3] += a[0];
+                dy[4] += a[1];
+                dy[5] += a[2];
+            }
+        }
     }
 }
 
@@ -38,7 +83,12 @@ Propagate a state vector for a given time of flight.
 * `final_position` - The final position of the spacecraft after propagation.
  */
 fn propagate(state_vector: Vector6<f64>) -> Vec<Vector6<f64>> {
-    let system = Orbit { mu: 1.327e11 }; // mu km-3/s-2
+    let system = Orbit {
+        mu: 1.327e11, // mu km-3/s-2
+        j2: 0.0,
+        r_eq: 0.0,
+        perturbing_bodies: vec![],
+    };
 
     let rtol: f64 = 1e-6;
     let atol: f64 = 1e-8;
@@ -96,4 +146,116 @@ mod tests {
         assert_eq!(final_value[4], -29.755390699878056);
         assert_eq!(final_value[5], -0.5964095238080424);
     }
+
+    /// Curtis, "Orbital Mechanics for Engineering Students", example 4.9: a
+    /// J2-perturbed LEO orbit exhibits nodal (RAAN) regression of roughly
+    /// -0.172 deg/h, secularly drifting the orbit plane westward.
+    #[test]
+    fn should_apply_j2_secular_raan_drift() {
+        let earth_mu = 398600.0; // km^3/s^2
+        let earth_r_eq = 6378.0; // km
+        let earth_j2 = 1.08263e-3;
+
+        let y0 = State::new(-2384.46, 5729.01, 3050.46, -7.36138, -2.98997, 1.64354);
+        let time_of_flight = 48.0 * 3600.0;
+
+        let raan_deg = |y: &State| -> f64 {
+            let h_x = y[1] * y[5] - y[2] * y[4];
+            let h_y = y[2] * y[3] - y[0] * y[5];
+            let n_x = -h_y;
+            let n_y = h_x;
+            n_y.atan2(n_x).to_degrees()
+        };
+        let raan0 = raan_deg(&y0);
+
+        let system = Orbit {
+            mu: earth_mu,
+            j2: earth_j2,
+            r_eq: earth_r_eq,
+            perturbing_bodies: vec![],
+        };
+        let mut stepper = Dopri5::new(system, 0.0, time_of_flight, 10.0, y0, 1e-10, 1e-12);
+        stepper
+            .integrate()
+            .expect("ERROR: Unable to integrate provided parameters.");
+        let final_value = stepper.y_out().last().unwrap();
+
+        let mut raan_drift = raan_deg(final_value) - raan0;
+        if raan_drift > 180.0 {
+            raan_drift -= 360.0;
+        } else if raan_drift < -180.0 {
+            raan_drift += 360.0;
+        }
+        let drift_per_hour = raan_drift / 48.0;
+
+        assert_relatively_eq(&drift_per_hour, &-0.172, 0.02);
+
+        // j2 = 0.0 must reproduce the pure two-body result exactly, i.e. no
+        // secular RAAN drift beyond numerical noise, and so clearly different
+        // from the J2-perturbed drift.
+        let kepler_system = Orbit {
+            mu: earth_mu,
+            j2: 0.0,
+            r_eq: earth_r_eq,
+            perturbing_bodies: vec![],
+        };
+        let mut kepler_stepper = Dopri5::new(kepler_system, 0.0, time_of_flight, 10.0, y0, 1e-10, 1e-12);
+        kepler_stepper
+            .integrate()
+            .expect("ERROR: Unable to integrate provided parameters.");
+        let kepler_final = kepler_stepper.y_out().last().unwrap();
+        let kepler_drift = raan_deg(kepler_final) - raan0;
+
+        assert!(kepler_drift.abs() < 1e-3);
+        assert!((kepler_drift - raan_drift).abs() > 1.0);
+    }
+
+    /// A static Moon third-body term should measurably perturb a
+    /// high-altitude (GEO-like) orbit relative to the pure two-body case.
+    #[test]
+    fn should_apply_third_body_perturbation() {
+        let earth_mu = 398600.0; // km^3/s^2
+        let moon_mu = 4902.8; // km^3/s^2
+
+        fn moon_position(_t: Time) -> Vector3<f64> {
+            Vector3::new(384400.0, 0.0, 0.0)
+        }
+
+        let y0 = State::new(42164.0, 0.0, 0.0, 0.0, 3.0747, 0.0);
+        let time_of_flight = 10.0 * 86400.0;
+
+        let two_body = Orbit {
+            mu: earth_mu,
+            j2: 0.0,
+            r_eq: 0.0,
+            perturbing_bodies: vec![],
+        };
+        let mut two_body_stepper = Dopri5::new(two_body, 0.0, time_of_flight, 10.0, y0, 1e-10, 1e-12);
+        two_body_stepper
+            .integrate()
+            .expect("ERROR: Unable to integrate provided parameters.");
+        let two_body_final = *two_body_stepper.y_out().last().unwrap();
+
+        let perturbed = Orbit {
+            mu: earth_mu,
+            j2: 0.0,
+            r_eq: 0.0,
+            perturbing_bodies: vec![PerturbingBody {
+                mu: moon_mu,
+                position: moon_position,
+            }],
+        };
+        let mut perturbed_stepper = Dopri5::new(perturbed, 0.0, time_of_flight, 10.0, y0, 1e-10, 1e-12);
+        perturbed_stepper
+            .integrate()
+            .expect("ERROR: Unable to integrate provided parameters.");
+        let perturbed_final = *perturbed_stepper.y_out().last().unwrap();
+
+        let position_delta = ((perturbed_final[0] - two_body_final[0]).powf(2.0)
+            + (perturbed_final[1] - two_body_final[1]).powf(2.0)
+            + (perturbed_final[2] - two_body_final[2]).powf(2.0))
+        .sqrt();
+
+        assert!(position_delta > 1.0);
+    }
 }
@@ -0,0 +1,180 @@
+use ode_solvers::dopri5::*;
+use ode_solvers::*;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Normal};
+use rayon::prelude::*;
+
+use crate::elements::{coe2rv, rv2coe, KeplerianElements};
+use crate::{Orbit, State};
+
+/// How the per-sample dispersion is expressed: per-component Gaussian sigma
+/// on the Cartesian state, or on the classical orbital elements.
+pub(crate) enum Dispersion {
+    Cartesian(State),
+    Keplerian {
+        a: f64,
+        e: f64,
+        i: f64,
+        raan: f64,
+        argp: f64,
+        nu: f64,
+    },
+}
+
+/// Configuration for a Monte Carlo dispersion run around a nominal two-body
+/// propagation of `nominal_state` under `mu` for `time_of_flight` seconds.
+pub(crate) struct MonteCarloConfig {
+    pub(crate) nominal_state: State,
+    pub(crate) mu: f64,
+    pub(crate) time_of_flight: f64,
+    pub(crate) dispersion: Dispersion,
+    pub(crate) sample_count: usize,
+    /// Fixing the seed makes a run reproducible; `None` seeds from entropy.
+    pub(crate) seed: Option<u64>,
+}
+
+/// The dispersed final states plus summary statistics of the resulting
+/// distribution.
+pub(crate) struct MonteCarloResult {
+    pub(crate) final_states: Vec<State>,
+    pub(crate) mean: State,
+    pub(crate) covariance: nalgebra::Matrix6<f64>,
+}
+
+fn sample_dispersed_state(
+    nominal_state: &State,
+    dispersion: &Dispersion,
+    mu: f64,
+    rng: &mut impl Rng,
+) -> State {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    match dispersion {
+        Dispersion::Cartesian(sigma) => {
+            let mut sampled = *nominal_state;
+            for idx in 0..6 {
+                sampled[idx] += sigma[idx] * normal.sample(rng);
+            }
+            sampled
+        }
+        Dispersion::Keplerian {
+            a,
+            e,
+            i,
+            raan,
+            argp,
+            nu,
+        } => {
+            let nominal_elements = rv2coe(nominal_state, mu);
+            let dispersed_elements = KeplerianElements {
+                a: nominal_elements.a + a * normal.sample(rng),
+                e: nominal_elements.e + e * normal.sample(rng),
+                i: nominal_elements.i + i * normal.sample(rng),
+                raan: nominal_elements.raan + raan * normal.sample(rng),
+                argp: nominal_elements.argp + argp * normal.sample(rng),
+                nu: nominal_elements.nu + nu * normal.sample(rng),
+            };
+            coe2rv(&dispersed_elements, mu)
+        }
+    }
+}
+
+fn mean_state(states: &[State]) -> State {
+    let sum = states
+        .iter()
+        .fold(State::zeros(), |acc, state| acc + state);
+    sum / states.len() as f64
+}
+
+fn covariance_matrix(states: &[State], mean: &State) -> nalgebra::Matrix6<f64> {
+    let mut covariance = nalgebra::Matrix6::zeros();
+    for state in states {
+        let deviation = state - mean;
+        covariance += deviation * deviation.transpose();
+    }
+    covariance / (states.len() as f64 - 1.0)
+}
+
+/**
+Run a Monte Carlo dispersion analysis around a nominal trajectory.
+
+Samples `config.sample_count` dispersed initial states, propagates each in
+parallel via a rayon thread pool, and returns the distribution of final
+states together with their mean and covariance.
+
+# Arguments
+* `config` - The nominal state, dispersion model, sample count and seed.
+
+# Returns
+* `MonteCarloResult` - The per-sample final states plus summary statistics.
+ */
+pub(crate) fn run_monte_carlo(config: &MonteCarloConfig) -> MonteCarloResult {
+    let final_states: Vec<State> = (0..config.sample_count)
+        .into_par_iter()
+        .map(|sample_index| {
+            let mut rng = match config.seed {
+                Some(seed) => rand::rngs::StdRng::seed_from_u64(seed.wrapping_add(sample_index as u64)),
+                None => rand::rngs::StdRng::from_entropy(),
+            };
+            let dispersed_state = sample_dispersed_state(
+                &config.nominal_state,
+                &config.dispersion,
+                config.mu,
+                &mut rng,
+            );
+
+            let system = Orbit {
+                mu: config.mu,
+                j2: 0.0,
+                r_eq: 0.0,
+                perturbing_bodies: vec![],
+            };
+            let mut stepper = Dopri5::new(
+                system,
+                0.0,
+                config.time_of_flight,
+                10.0,
+                dispersed_state,
+                1e-6,
+                1e-8,
+            );
+            stepper
+                .integrate()
+                .expect("ERROR: Unable to integrate provided parameters.");
+            *stepper.y_out().last().unwrap()
+        })
+        .collect();
+
+    let mean = mean_state(&final_states);
+    let covariance = covariance_matrix(&final_states, &mean);
+
+    MonteCarloResult {
+        final_states,
+        mean,
+        covariance,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_produce_reproducible_dispersion_with_fixed_seed() {
+        let config = MonteCarloConfig {
+            nominal_state: State::new(7000.0, 0.0, 0.0, 0.0, 7.546, 0.0),
+            mu: 398600.0,
+            time_of_flight: 3600.0,
+            dispersion: Dispersion::Cartesian(State::new(1.0, 1.0, 1.0, 0.001, 0.001, 0.001)),
+            sample_count: 20,
+            seed: Some(42),
+        };
+
+        let result_one = run_monte_carlo(&config);
+        let result_two = run_monte_carlo(&config);
+
+        for idx in 0..result_one.final_states.len() {
+            assert_eq!(result_one.final_states[idx], result_two.final_states[idx]);
+        }
+        assert_eq!(result_one.mean, result_two.mean);
+    }
+}
@@ -0,0 +1,221 @@
+use std::path::Path;
+
+use ode_solvers::dopri5::*;
+use ode_solvers::*;
+use serde::Deserialize;
+
+use crate::elements::{coe2rv, KeplerianElements};
+use crate::{Orbit, State, Time};
+
+/// The initial state of a scenario, given either directly in Cartesian
+/// coordinates or as classical orbital elements referenced to an epoch.
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum InitialState {
+    Cartesian {
+        x: f64,
+        y: f64,
+        z: f64,
+        vx: f64,
+        vy: f64,
+        vz: f64,
+    },
+    Keplerian {
+        a: f64,
+        e: f64,
+        i: f64,
+        raan: f64,
+        argp: f64,
+        nu: f64,
+        epoch: f64,
+    },
+}
+
+impl InitialState {
+    fn to_state(&self, mu: f64) -> State {
+        match *self {
+            InitialState::Cartesian {
+                x,
+                y,
+                z,
+                vx,
+                vy,
+                vz,
+            } => State::new(x, y, z, vx, vy, vz),
+            InitialState::Keplerian {
+                a,
+                e,
+                i,
+                raan,
+                argp,
+                nu,
+                ..
+            } => coe2rv(&KeplerianElements { a, e, i, raan, argp, nu }, mu),
+        }
+    }
+
+    /// The epoch the initial state is referenced to. Cartesian states carry
+    /// no epoch of their own, so they're treated as given at `t = 0.0`.
+    fn epoch(&self) -> Time {
+        match *self {
+            InitialState::Cartesian { .. } => 0.0,
+            InitialState::Keplerian { epoch, .. } => epoch,
+        }
+    }
+}
+
+/// Configuration for a single propagation run, loaded from a TOML or YAML
+/// file: the central-body `mu`, integrator tolerances/step, total duration,
+/// and initial state.
+#[derive(Deserialize)]
+pub(crate) struct Scenario {
+    /// Gravitational parameter of the central body, km^3/s^2.
+    mu: f64,
+    /// Relative tolerance passed to the `Dopri5` stepper.
+    rtol: f64,
+    /// Absolute tolerance passed to the `Dopri5` stepper.
+    atol: f64,
+    /// Dense-output step size passed to the `Dopri5` stepper.
+    dx: f64,
+    /// Total propagation duration, in seconds.
+    time_of_flight: f64,
+    /// The initial state, either Cartesian or Keplerian.
+    initial_state: InitialState,
+}
+
+/**
+Parse a scenario configuration file, construct the corresponding `Orbit`
+and `Dopri5` stepper, integrate it, and return the resulting trajectory.
+
+# Arguments
+* `path` - Path to a `.toml` or `.yaml`/`.yml` scenario file.
+
+# Returns
+* `trajectory` - The propagated state vectors.
+ */
+pub(crate) fn run_scenario(path: &Path) -> Vec<State> {
+    let contents = std::fs::read_to_string(path)
+        .unwrap_or_else(|_| panic!("ERROR: Unable to read scenario file {:?}.", path));
+
+    let scenario: Scenario = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&contents)
+            .expect("ERROR: Unable to parse scenario file as YAML."),
+        _ => toml::from_str(&contents).expect("ERROR: Unable to parse scenario file as TOML."),
+    };
+
+    let initial_state = scenario.initial_state.to_state(scenario.mu);
+
+    let system = Orbit {
+        mu: scenario.mu,
+        j2: 0.0,
+        r_eq: 0.0,
+        perturbing_bodies: vec![],
+    };
+
+    let time_start: Time = scenario.initial_state.epoch();
+    let mut stepper = Dopri5::new(
+        system,
+        time_start,
+        time_start + scenario.time_of_flight,
+        scenario.dx,
+        initial_state,
+        scenario.rtol,
+        scenario.atol,
+    );
+    stepper
+        .integrate()
+        .expect("ERROR: Unable to integrate provided parameters.");
+
+    stepper.y_out().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_relatively_eq(num_one: f64, num_two: f64, epsilon: f64) {
+        let diff = (num_two - num_one).abs();
+        assert!(diff <= epsilon, "{} vs {}, diff {}", num_one, num_two, diff);
+    }
+
+    fn write_scenario(file_name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(file_name);
+        std::fs::write(&path, contents).expect("ERROR: Unable to write scenario test file.");
+        path
+    }
+
+    #[test]
+    fn should_run_cartesian_scenario_from_toml() {
+        let path = write_scenario(
+            "state_propogator_test_scenario.toml",
+            r#"
+mu = 398600.0
+rtol = 1e-6
+atol = 1e-8
+dx = 10.0
+time_of_flight = 3600.0
+
+[initial_state.cartesian]
+x = 7000.0
+y = 0.0
+z = 0.0
+vx = 0.0
+vy = 7.546
+vz = 0.0
+"#,
+        );
+
+        let trajectory = run_scenario(&path);
+
+        assert!(!trajectory.is_empty());
+        let first = trajectory.first().unwrap();
+        assert_relatively_eq(first[0], 7000.0, 1e-9);
+        assert_relatively_eq(first[4], 7.546, 1e-9);
+
+        std::fs::remove_file(&path).expect("ERROR: Unable to remove scenario test file.");
+    }
+
+    #[test]
+    fn should_run_keplerian_scenario_from_yaml_with_epoch() {
+        let path = write_scenario(
+            "state_propogator_test_scenario.yaml",
+            r#"
+mu: 398600.0
+rtol: 1.0e-6
+atol: 1.0e-8
+dx: 10.0
+time_of_flight: 3600.0
+initial_state:
+  keplerian:
+    a: 7000.0
+    e: 0.01
+    i: 0.0
+    raan: 0.0
+    argp: 0.0
+    nu: 0.0
+    epoch: 100.0
+"#,
+        );
+
+        let trajectory = run_scenario(&path);
+
+        assert!(!trajectory.is_empty());
+        let expected_initial = coe2rv(
+            &KeplerianElements {
+                a: 7000.0,
+                e: 0.01,
+                i: 0.0,
+                raan: 0.0,
+                argp: 0.0,
+                nu: 0.0,
+            },
+            398600.0,
+        );
+        let first = trajectory.first().unwrap();
+        for idx in 0..6 {
+            assert_relatively_eq(first[idx], expected_initial[idx], 1e-6);
+        }
+
+        std::fs::remove_file(&path).expect("ERROR: Unable to remove scenario test file.");
+    }
+}
@@ -0,0 +1,120 @@
+use std::io::Write;
+use std::path::Path;
+
+use ode_solvers::dopri5::*;
+use ode_solvers::*;
+
+use crate::{Orbit, State, Time};
+
+/**
+Propagate a state vector for a given time of flight, retaining the dense
+time/state pairs the integrator produces along the way instead of only the
+final state.
+
+# Arguments
+* `state_vector` - The 1x6 array of cartesian position and velocity elements.
+* `mu` - Gravitational parameter of the central body, km^3/s^2.
+* `time_of_flight_sec` - The duration to propagate for, in seconds.
+* `step_sec` - The output cadence, passed to `Dopri5` as its dense-output step.
+
+# Returns
+* `trajectory` - The `(time, state)` pairs sampled along the integration.
+ */
+pub(crate) fn propagate_dense(
+    state_vector: State,
+    mu: f64,
+    time_of_flight_sec: f64,
+    step_sec: f64,
+) -> Vec<(Time, State)> {
+    let system = Orbit {
+        mu,
+        j2: 0.0,
+        r_eq: 0.0,
+        perturbing_bodies: vec![],
+    };
+
+    let rtol: f64 = 1e-6;
+    let atol: f64 = 1e-8;
+
+    let mut stepper = Dopri5::new(
+        system,
+        0.0,
+        time_of_flight_sec,
+        step_sec,
+        state_vector,
+        rtol,
+        atol,
+    );
+    stepper
+        .integrate()
+        .expect("ERROR: Unable to integrate provided parameters.");
+
+    stepper
+        .x_out()
+        .iter()
+        .cloned()
+        .zip(stepper.y_out().iter().cloned())
+        .collect()
+}
+
+/**
+Propagate a state vector and write the resulting trajectory to a whitespace
+`.dat` file as `time x y z vx vy vz` rows.
+
+# Arguments
+* `path` - Destination file path.
+* `state_vector` - The 1x6 array of cartesian position and velocity elements.
+* `mu` - Gravitational parameter of the central body, km^3/s^2.
+* `time_of_flight_sec` - The duration to propagate for, in seconds.
+* `step_sec` - The output cadence, passed to `Dopri5` as its dense-output step.
+ */
+pub(crate) fn save_trajectory(
+    path: &Path,
+    state_vector: State,
+    mu: f64,
+    time_of_flight_sec: f64,
+    step_sec: f64,
+) -> std::io::Result<()> {
+    let trajectory = propagate_dense(state_vector, mu, time_of_flight_sec, step_sec);
+
+    let mut file = std::fs::File::create(path)?;
+    for (t, state) in &trajectory {
+        writeln!(
+            file,
+            "{} {} {} {} {} {} {}",
+            t, state[0], state[1], state[2], state[3], state[4], state[5]
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_save_trajectory_with_paired_time_stamps() {
+        let y0 = State::new(
+            -131386230.977293,
+            69971484.9501445,
+            -718889.822774674,
+            -1.745306e+01,
+            -2.843202e+01,
+            -6.151334e-01,
+        );
+        let path = std::env::temp_dir().join("state_propogator_test_trajectory.dat");
+
+        save_trajectory(&path, y0, 1.327e11, 86400.0, 3600.0)
+            .expect("ERROR: Unable to write trajectory file.");
+
+        let contents = std::fs::read_to_string(&path).expect("ERROR: Unable to read trajectory file.");
+        let lines: Vec<&str> = contents.lines().collect();
+
+        assert!(lines.len() > 1);
+        let first_row: Vec<&str> = lines[0].split_whitespace().collect();
+        assert_eq!(first_row.len(), 7);
+        assert_eq!(first_row[0].parse::<f64>().unwrap(), 0.0);
+
+        std::fs::remove_file(&path).expect("ERROR: Unable to remove trajectory test file.");
+    }
+}